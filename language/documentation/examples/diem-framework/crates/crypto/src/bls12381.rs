@@ -0,0 +1,528 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module provides an API for the BLS signature scheme over the BLS12-381 curve, following
+//! the [IETF BLS signature draft](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-04)
+//! in the "minimal-pubkey-size" variant (public keys in G1, signatures in G2) with the
+//! proof-of-possession (PoP) ciphersuite.
+//!
+//! Signatures on the same message can be aggregated into a single signature, which verifies
+//! against the aggregate of the signers' public keys. To prevent rogue-key attacks, every public
+//! key that takes part in an aggregation must first have its [`Bls12381ProofOfPossession`]
+//! verified.
+//!
+//! # Examples
+//!
+//! ```
+//! use diem_crypto_derive::{CryptoHasher, BCSCryptoHash};
+//! use diem_crypto::{
+//!     bls12381::*,
+//!     traits::{Signature, SigningKey, Uniform},
+//! };
+//! use rand::{rngs::StdRng, SeedableRng};
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, CryptoHasher, BCSCryptoHash)]
+//! pub struct TestCryptoDocTest(String);
+//! let message = TestCryptoDocTest("Test message".to_string());
+//!
+//! let mut rng: StdRng = SeedableRng::from_seed([0; 32]);
+//! let private_key = Bls12381PrivateKey::generate(&mut rng);
+//! let public_key: Bls12381PublicKey = (&private_key).into();
+//! let pop = Bls12381ProofOfPossession::create(&private_key);
+//! assert!(pop.verify(&public_key).is_ok());
+//! let signature = private_key.sign(&message);
+//! assert!(signature.verify(&message, &public_key).is_ok());
+//! ```
+//! **Note**: The above example generates a private key using a private function intended only for
+//! testing purposes. Production code should find an alternate means for secure key generation.
+
+use crate::{
+    hash::{CryptoHash, CryptoHasher},
+    traits::*,
+};
+use anyhow::{anyhow, Result};
+use blst::{min_pk as blst_core, BLST_ERROR};
+use core::convert::TryFrom;
+use diem_crypto_derive::{DeserializeKey, SerializeKey, SilentDebug, SilentDisplay};
+use serde::Serialize;
+use std::fmt;
+
+pub use blst;
+
+/// The length of the Bls12381PrivateKey
+pub const BLS12381_PRIVATE_KEY_LENGTH: usize = 32;
+/// The length of a compressed Bls12381PublicKey
+pub const BLS12381_PUBLIC_KEY_LENGTH: usize = 48;
+/// The length of a compressed Bls12381Signature (and Bls12381ProofOfPossession)
+pub const BLS12381_SIGNATURE_LENGTH: usize = 96;
+
+/// The domain separation tag (DST) used when hashing messages to G2 for signing.
+const DST_BLS_SIG_IN_G2_WITH_POP: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// The domain separation tag (DST) used when hashing public keys to G2 for proofs-of-possession.
+const DST_BLS_POP_IN_G2: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// A BLS12-381 private key
+#[derive(DeserializeKey, SerializeKey, SilentDebug, SilentDisplay)]
+pub struct Bls12381PrivateKey(blst_core::SecretKey);
+
+#[cfg(feature = "assert-private-keys-not-cloneable")]
+static_assertions::assert_not_impl_any!(Bls12381PrivateKey: Clone);
+
+#[cfg(any(test, feature = "cloneable-private-keys"))]
+impl Clone for Bls12381PrivateKey {
+    fn clone(&self) -> Self {
+        let serialized: &[u8] = &(self.to_bytes());
+        Bls12381PrivateKey::try_from(serialized).unwrap()
+    }
+}
+
+/// A BLS12-381 public key, i.e. a point in the prime-order subgroup of G1
+#[derive(DeserializeKey, Clone, SerializeKey)]
+pub struct Bls12381PublicKey(blst_core::PublicKey);
+
+/// A BLS12-381 signature, i.e. a point in the prime-order subgroup of G2. This is either a
+/// signature by a single signer or the aggregate of several signatures on the same message.
+#[derive(DeserializeKey, Clone, SerializeKey)]
+pub struct Bls12381Signature(blst_core::Signature);
+
+/// A proof-of-possession (PoP) of a BLS12-381 private key, i.e. a signature on the corresponding
+/// public key under a dedicated domain separation tag.
+#[derive(DeserializeKey, Clone, SerializeKey)]
+pub struct Bls12381ProofOfPossession(blst_core::Signature);
+
+impl Bls12381PrivateKey {
+    /// The length of the Bls12381PrivateKey
+    pub const LENGTH: usize = BLS12381_PRIVATE_KEY_LENGTH;
+
+    /// Serialize a Bls12381PrivateKey.
+    pub fn to_bytes(&self) -> [u8; BLS12381_PRIVATE_KEY_LENGTH] {
+        self.0.to_bytes()
+    }
+
+    /// Private function aimed at minimizing code duplication between sign
+    /// methods of the SigningKey implementation. This should remain private.
+    fn sign_arbitrary_message(&self, message: &[u8]) -> Bls12381Signature {
+        Bls12381Signature(self.0.sign(message, DST_BLS_SIG_IN_G2_WITH_POP, &[]))
+    }
+}
+
+impl Bls12381PublicKey {
+    /// Serialize a Bls12381PublicKey in compressed form.
+    pub fn to_bytes(&self) -> [u8; BLS12381_PUBLIC_KEY_LENGTH] {
+        self.0.to_bytes()
+    }
+
+    /// Aggregates the given public keys into a single public key, against which the aggregate of
+    /// their signatures on a common message verifies.
+    ///
+    /// WARNING: the proof-of-possession of every key must have been verified beforehand, as the
+    /// aggregate is otherwise vulnerable to rogue-key attacks.
+    pub fn aggregate(public_keys: &[&Self]) -> Result<Self> {
+        let blst_public_keys: Vec<_> = public_keys.iter().map(|pk| &pk.0).collect();
+        // The keys were subgroup-checked on deserialization, so there is no need to re-validate.
+        let aggregate = blst_core::AggregatePublicKey::aggregate(&blst_public_keys[..], false)
+            .map_err(|e| anyhow!("{:?}", e))?;
+        Ok(Bls12381PublicKey(aggregate.to_public_key()))
+    }
+}
+
+impl Bls12381Signature {
+    /// The length of the Bls12381Signature
+    pub const LENGTH: usize = BLS12381_SIGNATURE_LENGTH;
+
+    /// Serialize a Bls12381Signature in compressed form.
+    pub fn to_bytes(&self) -> [u8; BLS12381_SIGNATURE_LENGTH] {
+        self.0.to_bytes()
+    }
+
+    /// Deserialize a Bls12381Signature without checking that it lies in the prime-order
+    /// subgroup of G2; the check is instead performed on verification.
+    pub(crate) fn from_bytes_unchecked(
+        bytes: &[u8],
+    ) -> std::result::Result<Bls12381Signature, CryptoMaterialError> {
+        if bytes.len() != BLS12381_SIGNATURE_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        match blst_core::Signature::from_bytes(bytes) {
+            Ok(blst_signature) => Ok(Bls12381Signature(blst_signature)),
+            Err(_) => Err(CryptoMaterialError::DeserializationError),
+        }
+    }
+
+    /// Aggregates signatures, all on the same message, into a single signature which verifies
+    /// against the [aggregate](Bls12381PublicKey::aggregate) of the signers' public keys.
+    pub fn aggregate(signatures: &[&Self]) -> Result<Self> {
+        let blst_signatures: Vec<_> = signatures.iter().map(|sig| &sig.0).collect();
+        // Subgroup checks are deferred to the verification of the aggregate.
+        let aggregate = blst_core::AggregateSignature::aggregate(&blst_signatures[..], false)
+            .map_err(|e| anyhow!("{:?}", e))?;
+        Ok(Bls12381Signature(aggregate.to_signature()))
+    }
+
+    /// Verifies an aggregate signature on a struct we unambiguously know how to serialize,
+    /// signed by all of `public_keys`. This is equivalent to, but faster than, aggregating the
+    /// public keys and calling [`Signature::verify`].
+    ///
+    /// WARNING: the proof-of-possession of every key must have been verified beforehand.
+    pub fn verify_aggregate<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_keys: &[&Bls12381PublicKey],
+    ) -> Result<()> {
+        let message_bytes = signing_message(message);
+        let blst_public_keys: Vec<_> = public_keys.iter().map(|pk| &pk.0).collect();
+        let result = self.0.fast_aggregate_verify(
+            true,
+            &message_bytes,
+            DST_BLS_SIG_IN_G2_WITH_POP,
+            &blst_public_keys[..],
+        );
+        blst_result(result, "BLS12-381 aggregate signature verification failed")
+    }
+}
+
+impl Bls12381ProofOfPossession {
+    /// Creates a proof-of-possession of `private_key` by signing its public key.
+    pub fn create(private_key: &Bls12381PrivateKey) -> Self {
+        let public_key = Bls12381PublicKey::from(private_key);
+        Bls12381ProofOfPossession(private_key.0.sign(
+            &public_key.to_bytes(),
+            DST_BLS_POP_IN_G2,
+            &[],
+        ))
+    }
+
+    /// Verifies that this is a valid proof-of-possession of the private key for `public_key`.
+    pub fn verify(&self, public_key: &Bls12381PublicKey) -> Result<()> {
+        let result = self.0.verify(
+            true,
+            &public_key.to_bytes(),
+            DST_BLS_POP_IN_G2,
+            &[],
+            &public_key.0,
+            false,
+        );
+        blst_result(result, "BLS12-381 proof-of-possession verification failed")
+    }
+
+    /// Serialize a Bls12381ProofOfPossession in compressed form.
+    pub fn to_bytes(&self) -> [u8; BLS12381_SIGNATURE_LENGTH] {
+        self.0.to_bytes()
+    }
+}
+
+/// Maps a blst verification status to a `Result`.
+fn blst_result(result: BLST_ERROR, message: &str) -> Result<()> {
+    match result {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        e => Err(anyhow!("{}: {:?}", message, e)),
+    }
+}
+
+///////////////////////
+// PrivateKey Traits //
+///////////////////////
+
+impl PrivateKey for Bls12381PrivateKey {
+    type PublicKeyMaterial = Bls12381PublicKey;
+}
+
+impl SigningKey for Bls12381PrivateKey {
+    type VerifyingKeyMaterial = Bls12381PublicKey;
+    type SignatureMaterial = Bls12381Signature;
+
+    fn sign<T: CryptoHash + Serialize>(&self, message: &T) -> Bls12381Signature {
+        Bls12381PrivateKey::sign_arbitrary_message(self, signing_message(message).as_ref())
+    }
+
+    #[cfg(any(test, feature = "fuzzing"))]
+    fn sign_arbitrary_message(&self, message: &[u8]) -> Bls12381Signature {
+        Bls12381PrivateKey::sign_arbitrary_message(self, message)
+    }
+}
+
+impl Uniform for Bls12381PrivateKey {
+    fn generate<R>(rng: &mut R) -> Self
+    where
+        R: ::rand::RngCore + ::rand::CryptoRng,
+    {
+        // KeyGen requires at least 32 bytes of input keying material.
+        let mut ikm = [0u8; 32];
+        rng.fill_bytes(&mut ikm);
+        let secret_key = blst_core::SecretKey::key_gen(&ikm, &[])
+            .expect("32 bytes of keying material should always be enough for KeyGen");
+        Bls12381PrivateKey(secret_key)
+    }
+}
+
+impl PartialEq<Self> for Bls12381PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for Bls12381PrivateKey {}
+
+impl TryFrom<&[u8]> for Bls12381PrivateKey {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a Bls12381PrivateKey. This method will also check that the key is a non-zero
+    /// scalar smaller than the order of the BLS12-381 groups.
+    fn try_from(bytes: &[u8]) -> std::result::Result<Bls12381PrivateKey, CryptoMaterialError> {
+        if bytes.len() != BLS12381_PRIVATE_KEY_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        match blst_core::SecretKey::from_bytes(bytes) {
+            Ok(blst_secret_key) => Ok(Bls12381PrivateKey(blst_secret_key)),
+            Err(_) => Err(CryptoMaterialError::DeserializationError),
+        }
+    }
+}
+
+impl Length for Bls12381PrivateKey {
+    fn length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Bls12381PrivateKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+//////////////////////
+// PublicKey Traits //
+//////////////////////
+
+impl From<&Bls12381PrivateKey> for Bls12381PublicKey {
+    fn from(private_key: &Bls12381PrivateKey) -> Self {
+        Bls12381PublicKey(private_key.0.sk_to_pk())
+    }
+}
+
+impl PublicKey for Bls12381PublicKey {
+    type PrivateKeyMaterial = Bls12381PrivateKey;
+}
+
+impl std::hash::Hash for Bls12381PublicKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let encoded_pubkey = self.to_bytes();
+        state.write(&encoded_pubkey);
+    }
+}
+
+impl PartialEq for Bls12381PublicKey {
+    fn eq(&self, other: &Bls12381PublicKey) -> bool {
+        self.to_bytes()[..] == other.to_bytes()[..]
+    }
+}
+
+impl Eq for Bls12381PublicKey {}
+
+impl VerifyingKey for Bls12381PublicKey {
+    type SigningKeyMaterial = Bls12381PrivateKey;
+    type SignatureMaterial = Bls12381Signature;
+}
+
+impl fmt::Display for Bls12381PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+impl fmt::Debug for Bls12381PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bls12381PublicKey({})", self)
+    }
+}
+
+impl TryFrom<&[u8]> for Bls12381PublicKey {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a compressed Bls12381PublicKey. This method will also check that the key is
+    /// not the identity and lies in the prime-order subgroup of G1, which guards against small
+    /// subgroup attacks.
+    fn try_from(bytes: &[u8]) -> std::result::Result<Bls12381PublicKey, CryptoMaterialError> {
+        if bytes.len() != BLS12381_PUBLIC_KEY_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        let public_key = blst_core::PublicKey::from_bytes(bytes)
+            .map_err(|_| CryptoMaterialError::DeserializationError)?;
+        public_key.validate().map_err(|e| match e {
+            BLST_ERROR::BLST_POINT_NOT_ON_CURVE => CryptoMaterialError::PointNotOnCurveError,
+            BLST_ERROR::BLST_POINT_NOT_IN_GROUP => CryptoMaterialError::SmallSubgroupError,
+            _ => CryptoMaterialError::ValidationError,
+        })?;
+        Ok(Bls12381PublicKey(public_key))
+    }
+}
+
+impl Length for Bls12381PublicKey {
+    fn length(&self) -> usize {
+        BLS12381_PUBLIC_KEY_LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Bls12381PublicKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+//////////////////////
+// Signature Traits //
+//////////////////////
+
+impl Signature for Bls12381Signature {
+    type VerifyingKeyMaterial = Bls12381PublicKey;
+    type SigningKeyMaterial = Bls12381PrivateKey;
+
+    /// Verifies that the provided signature is valid for the provided message, according to the
+    /// CoreVerify algorithm of the IETF draft.
+    fn verify<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_key: &Bls12381PublicKey,
+    ) -> Result<()> {
+        let mut bytes = <T::Hasher as CryptoHasher>::seed().to_vec();
+        bcs::serialize_into(&mut bytes, &message)
+            .map_err(|_| CryptoMaterialError::SerializationError)?;
+        Self::verify_arbitrary_msg(self, &bytes, public_key)
+    }
+
+    /// Checks that `self` is valid for an arbitrary &[u8] `message` using `public_key`.
+    /// Outside of this crate, this particular function should only be used for native signature
+    /// verification in move
+    fn verify_arbitrary_msg(&self, message: &[u8], public_key: &Bls12381PublicKey) -> Result<()> {
+        // The public key was validated on deserialization, but the signature still has to be
+        // subgroup-checked.
+        let result = self.0.verify(
+            true,
+            message,
+            DST_BLS_SIG_IN_G2_WITH_POP,
+            &[],
+            &public_key.0,
+            false,
+        );
+        blst_result(result, "BLS12-381 signature verification failed")
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl Length for Bls12381Signature {
+    fn length(&self) -> usize {
+        BLS12381_SIGNATURE_LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Bls12381Signature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl std::hash::Hash for Bls12381Signature {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let encoded_signature = self.to_bytes();
+        state.write(&encoded_signature);
+    }
+}
+
+impl TryFrom<&[u8]> for Bls12381Signature {
+    type Error = CryptoMaterialError;
+
+    fn try_from(bytes: &[u8]) -> std::result::Result<Bls12381Signature, CryptoMaterialError> {
+        Bls12381Signature::from_bytes_unchecked(bytes)
+    }
+}
+
+impl PartialEq for Bls12381Signature {
+    fn eq(&self, other: &Bls12381Signature) -> bool {
+        self.to_bytes()[..] == other.to_bytes()[..]
+    }
+}
+
+impl Eq for Bls12381Signature {}
+
+impl fmt::Display for Bls12381Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+impl fmt::Debug for Bls12381Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bls12381Signature({})", self)
+    }
+}
+
+//////////////////////////////
+// ProofOfPossession Traits //
+//////////////////////////////
+
+impl TryFrom<&[u8]> for Bls12381ProofOfPossession {
+    type Error = CryptoMaterialError;
+
+    fn try_from(
+        bytes: &[u8],
+    ) -> std::result::Result<Bls12381ProofOfPossession, CryptoMaterialError> {
+        let signature = Bls12381Signature::from_bytes_unchecked(bytes)?;
+        Ok(Bls12381ProofOfPossession(signature.0))
+    }
+}
+
+impl ValidCryptoMaterial for Bls12381ProofOfPossession {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl PartialEq for Bls12381ProofOfPossession {
+    fn eq(&self, other: &Bls12381ProofOfPossession) -> bool {
+        self.to_bytes()[..] == other.to_bytes()[..]
+    }
+}
+
+impl Eq for Bls12381ProofOfPossession {}
+
+impl fmt::Display for Bls12381ProofOfPossession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+impl fmt::Debug for Bls12381ProofOfPossession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bls12381ProofOfPossession({})", self)
+    }
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+use crate::test_utils::{self, KeyPair};
+
+/// Produces a uniformly random BLS12-381 keypair from a seed
+#[cfg(any(test, feature = "fuzzing"))]
+pub fn keypair_strategy() -> impl Strategy<Value = KeyPair<Bls12381PrivateKey, Bls12381PublicKey>> {
+    test_utils::uniform_keypair_strategy::<Bls12381PrivateKey, Bls12381PublicKey>()
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::prelude::*;
+
+#[cfg(any(test, feature = "fuzzing"))]
+impl proptest::arbitrary::Arbitrary for Bls12381PublicKey {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        crate::test_utils::uniform_keypair_strategy::<Bls12381PrivateKey, Bls12381PublicKey>()
+            .prop_map(|v| v.public_key)
+            .boxed()
+    }
+}
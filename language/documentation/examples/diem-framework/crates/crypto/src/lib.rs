@@ -8,13 +8,17 @@
 #![cfg_attr(mirai, allow(incomplete_features), feature(const_generics))]
 
 //! A library supplying various cryptographic primitives
+pub mod bls12381;
 pub mod compat;
 pub mod ed25519;
 pub mod error;
 pub mod hash;
 pub mod hkdf;
+pub mod mnemonic;
 pub mod multi_ed25519;
 pub mod noise;
+pub mod secp256k1;
+pub mod slip0010;
 pub mod test_utils;
 pub mod traits;
 pub mod validatable;
@@ -0,0 +1,83 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mnemonic phrases as specified in
+//! [BIP39](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki), using the English
+//! word list.
+//!
+//! A mnemonic encodes a random secret as a sequence of words that is easy to write down, and is
+//! stretched into a 64-byte seed from which keys can be [derived](crate::slip0010).
+//!
+//! # Example
+//!
+//! ```
+//! use diem_crypto::{
+//!     ed25519::Ed25519PrivateKey,
+//!     mnemonic::Mnemonic,
+//!     slip0010::{DerivationPath, ExtendedKey},
+//! };
+//! use rand::{rngs::StdRng, SeedableRng};
+//! use std::str::FromStr;
+//!
+//! let mut rng: StdRng = SeedableRng::from_seed([0; 32]);
+//! let mnemonic = Mnemonic::generate(&mut rng, 24).unwrap();
+//! let restored = Mnemonic::from_phrase(&mnemonic.phrase()).unwrap();
+//! assert_eq!(mnemonic.to_seed(""), restored.to_seed(""));
+//!
+//! let path = DerivationPath::from_str("m/44'/637'/0'/0'/0'").unwrap();
+//! let key = ExtendedKey::<Ed25519PrivateKey>::derive_from_seed(&mnemonic.to_seed(""), &path);
+//! assert!(key.is_ok());
+//! ```
+//! **Note**: The above example generates a mnemonic using a deterministic RNG intended only for
+//! testing purposes. Production code should use a cryptographically secure source of randomness.
+
+use anyhow::Result;
+use bip39::Language;
+use diem_crypto_derive::{SilentDebug, SilentDisplay};
+use rand::{CryptoRng, RngCore};
+
+/// The length of the seed derived from a mnemonic.
+pub const MNEMONIC_SEED_LENGTH: usize = 64;
+
+/// A BIP39 mnemonic phrase.
+#[derive(SilentDebug, SilentDisplay)]
+pub struct Mnemonic(bip39::Mnemonic);
+
+impl Mnemonic {
+    /// Generates a mnemonic of `word_count` words, which must be one of 12, 15, 18, 21 or 24.
+    pub fn generate<R>(rng: &mut R, word_count: usize) -> Result<Self>
+    where
+        R: RngCore + CryptoRng,
+    {
+        // Each word encodes 11 bits, of which one in 33 is a checksum bit.
+        if word_count % 3 != 0 {
+            return Err(bip39::Error::BadWordCount(word_count).into());
+        }
+        let mut entropy = vec![0u8; word_count * 4 / 3];
+        rng.fill_bytes(&mut entropy);
+        Ok(Mnemonic(bip39::Mnemonic::from_entropy_in(
+            Language::English,
+            &entropy,
+        )?))
+    }
+
+    /// Parses a mnemonic phrase, checking that all words are in the word list and that the
+    /// checksum is correct.
+    pub fn from_phrase(phrase: &str) -> Result<Self> {
+        Ok(Mnemonic(bip39::Mnemonic::parse_in(
+            Language::English,
+            phrase,
+        )?))
+    }
+
+    /// Returns the mnemonic phrase.
+    pub fn phrase(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Stretches the mnemonic, along with an optional `passphrase` (possibly empty), into a seed.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; MNEMONIC_SEED_LENGTH] {
+        self.0.to_seed(passphrase)
+    }
+}
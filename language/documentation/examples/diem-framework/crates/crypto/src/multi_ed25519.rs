@@ -6,6 +6,10 @@
 //! over the ed25519 twisted Edwards curve as defined in [RFC8032](https://tools.ietf.org/html/rfc8032).
 //!
 //! Signature verification also checks and rejects non-canonical signatures.
+//!
+//! When the signers of a multi-key policy hold their keys independently, a
+//! [`MultiEd25519SignatureAggregator`] collects and checks their individual signatures until the
+//! policy threshold is met.
 
 use crate::{
     ed25519::{
@@ -21,7 +25,7 @@ use diem_crypto_derive::{DeserializeKey, SerializeKey, SilentDebug, SilentDispla
 use mirai_annotations::*;
 use rand::Rng;
 use serde::Serialize;
-use std::{convert::TryInto, fmt};
+use std::{collections::BTreeMap, convert::TryInto, fmt};
 
 const MAX_NUM_OF_KEYS: usize = 32;
 const BITMAP_NUM_OF_BYTES: usize = 4;
@@ -416,6 +420,82 @@ impl MultiEd25519Signature {
     }
 }
 
+/// Collects the signatures of the individual signers of a MultiEd25519PublicKey policy on a given
+/// message, until enough of them are gathered to meet the policy threshold.
+///
+/// Every signature is verified as it is added, so that an invalid contribution is attributed to
+/// its signer rather than only surfacing when the final MultiEd25519Signature is verified.
+pub struct MultiEd25519SignatureAggregator {
+    public_key: MultiEd25519PublicKey,
+    message: Vec<u8>,
+    signatures: BTreeMap<u8, Ed25519Signature>,
+}
+
+impl MultiEd25519SignatureAggregator {
+    /// Starts collecting signatures on `message` for the policy `public_key`.
+    pub fn new<T: CryptoHash + Serialize>(public_key: MultiEd25519PublicKey, message: &T) -> Self {
+        MultiEd25519SignatureAggregator {
+            public_key,
+            message: signing_message(message),
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the signature of the signer at position `index` in the policy.
+    pub fn add_signature(&mut self, index: u8, signature: Ed25519Signature) -> Result<()> {
+        let signer = self
+            .public_key
+            .public_keys
+            .get(index as usize)
+            .ok_or_else(|| {
+                anyhow!(
+                    "{}",
+                    CryptoMaterialError::BitVecError("Signature index is out of range".to_string())
+                )
+            })?;
+        if self.signatures.contains_key(&index) {
+            return Err(anyhow!(
+                "{}",
+                CryptoMaterialError::BitVecError("Duplicate signature index".to_string())
+            ));
+        }
+        signature
+            .verify_arbitrary_msg(&self.message, signer)
+            .map_err(|e| anyhow!("Invalid signature for signer {}: {}", index, e))?;
+        self.signatures.insert(index, signature);
+        Ok(())
+    }
+
+    /// The positions in the policy of the signers whose signatures have been collected so far.
+    pub fn signers(&self) -> Vec<u8> {
+        self.signatures.keys().cloned().collect()
+    }
+
+    /// Whether enough signatures have been collected to meet the policy threshold.
+    pub fn is_complete(&self) -> bool {
+        self.signatures.len() >= self.public_key.threshold as usize
+    }
+
+    /// Assembles the collected signatures into a MultiEd25519Signature, failing if they do not
+    /// meet the policy threshold.
+    pub fn finish(self) -> Result<MultiEd25519Signature> {
+        if !self.is_complete() {
+            return Err(anyhow!(
+                "{}",
+                CryptoMaterialError::BitVecError(
+                    "Not enough signatures to meet the threshold".to_string()
+                )
+            ));
+        }
+        let signatures = self
+            .signatures
+            .into_iter()
+            .map(|(index, signature)| (signature, index))
+            .collect();
+        Ok(MultiEd25519Signature::new(signatures)?)
+    }
+}
+
 //////////////////////
 // Signature Traits //
 //////////////////////
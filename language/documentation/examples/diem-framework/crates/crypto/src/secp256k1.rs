@@ -0,0 +1,407 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module provides an API for the ECDSA signature scheme over the secp256k1 curve, as
+//! defined in [SEC 1](https://www.secg.org/sec1-v2.pdf) and [SEC 2](https://www.secg.org/sec2-v2.pdf).
+//!
+//! Messages are hashed with SHA3-256 before being signed. Public keys are serialized in the
+//! 33-byte compressed SEC 1 form, and signatures in the 64-byte compact `(r, s)` form.
+//!
+//! Signature verification also checks and rejects non-canonical signatures, i.e. signatures whose
+//! `s` component lies in the upper half of the curve order.
+//!
+//! # Examples
+//!
+//! ```
+//! use diem_crypto_derive::{CryptoHasher, BCSCryptoHash};
+//! use diem_crypto::{
+//!     secp256k1::*,
+//!     traits::{Signature, SigningKey, Uniform},
+//! };
+//! use rand::{rngs::StdRng, SeedableRng};
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, CryptoHasher, BCSCryptoHash)]
+//! pub struct TestCryptoDocTest(String);
+//! let message = TestCryptoDocTest("Test message".to_string());
+//!
+//! let mut rng: StdRng = SeedableRng::from_seed([0; 32]);
+//! let private_key = Secp256k1PrivateKey::generate(&mut rng);
+//! let public_key: Secp256k1PublicKey = (&private_key).into();
+//! let signature = private_key.sign(&message);
+//! assert!(signature.verify(&message, &public_key).is_ok());
+//! ```
+//! **Note**: The above example generates a private key using a private function intended only for
+//! testing purposes. Production code should find an alternate means for secure key generation.
+
+use crate::{
+    hash::{CryptoHash, CryptoHasher, HashValue},
+    traits::*,
+};
+use anyhow::{anyhow, Result};
+use core::convert::TryFrom;
+use diem_crypto_derive::{DeserializeKey, SerializeKey, SilentDebug, SilentDisplay};
+use serde::Serialize;
+use std::fmt;
+
+pub use libsecp256k1;
+
+/// The length of the Secp256k1PrivateKey
+pub const SECP256K1_PRIVATE_KEY_LENGTH: usize = libsecp256k1::util::SECRET_KEY_SIZE;
+/// The length of a compressed Secp256k1PublicKey
+pub const SECP256K1_PUBLIC_KEY_LENGTH: usize = libsecp256k1::util::COMPRESSED_PUBLIC_KEY_SIZE;
+/// The length of the Secp256k1Signature
+pub const SECP256K1_SIGNATURE_LENGTH: usize = libsecp256k1::util::SIGNATURE_SIZE;
+
+/// A secp256k1 ECDSA private key
+#[derive(DeserializeKey, SerializeKey, SilentDebug, SilentDisplay)]
+pub struct Secp256k1PrivateKey(libsecp256k1::SecretKey);
+
+#[cfg(feature = "assert-private-keys-not-cloneable")]
+static_assertions::assert_not_impl_any!(Secp256k1PrivateKey: Clone);
+
+#[cfg(any(test, feature = "cloneable-private-keys"))]
+impl Clone for Secp256k1PrivateKey {
+    fn clone(&self) -> Self {
+        let serialized: &[u8] = &(self.to_bytes());
+        Secp256k1PrivateKey::try_from(serialized).unwrap()
+    }
+}
+
+/// A secp256k1 ECDSA public key
+#[derive(DeserializeKey, Clone, SerializeKey)]
+pub struct Secp256k1PublicKey(libsecp256k1::PublicKey);
+
+/// A secp256k1 ECDSA signature
+#[derive(DeserializeKey, Clone, SerializeKey)]
+pub struct Secp256k1Signature(libsecp256k1::Signature);
+
+impl Secp256k1PrivateKey {
+    /// The length of the Secp256k1PrivateKey
+    pub const LENGTH: usize = SECP256K1_PRIVATE_KEY_LENGTH;
+
+    /// Serialize a Secp256k1PrivateKey.
+    pub fn to_bytes(&self) -> [u8; SECP256K1_PRIVATE_KEY_LENGTH] {
+        self.0.serialize()
+    }
+
+    /// Private function aimed at minimizing code duplication between sign
+    /// methods of the SigningKey implementation. This should remain private.
+    fn sign_arbitrary_message(&self, message: &[u8]) -> Secp256k1Signature {
+        let digest = message_digest(message);
+        // The underlying implementation always produces signatures with a low `s`.
+        let (signature, _recovery_id) = libsecp256k1::sign(&digest, &self.0);
+        Secp256k1Signature(signature)
+    }
+}
+
+impl Secp256k1PublicKey {
+    /// Serialize a Secp256k1PublicKey in compressed form.
+    pub fn to_bytes(&self) -> [u8; SECP256K1_PUBLIC_KEY_LENGTH] {
+        self.0.serialize_compressed()
+    }
+}
+
+impl Secp256k1Signature {
+    /// The length of the Secp256k1Signature
+    pub const LENGTH: usize = SECP256K1_SIGNATURE_LENGTH;
+
+    /// Serialize a Secp256k1Signature.
+    pub fn to_bytes(&self) -> [u8; SECP256K1_SIGNATURE_LENGTH] {
+        self.0.serialize()
+    }
+
+    /// Deserialize a Secp256k1Signature without any validation checks (malleability)
+    /// apart from expected size and `r`, `s` being valid scalars.
+    pub(crate) fn from_bytes_unchecked(
+        bytes: &[u8],
+    ) -> std::result::Result<Secp256k1Signature, CryptoMaterialError> {
+        if bytes.len() != SECP256K1_SIGNATURE_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        match libsecp256k1::Signature::parse_standard_slice(bytes) {
+            Ok(signature) => Ok(Secp256k1Signature(signature)),
+            Err(_) => Err(CryptoMaterialError::DeserializationError),
+        }
+    }
+
+    /// Check for correct size and third-party based signature malleability issues.
+    ///
+    /// Given a valid ECDSA signature `(r, s)`, `(r, n - s)` (where `n` is the order of the curve)
+    /// is also valid for the same message and key. To prevent this, we only accept signatures
+    /// whose `s` is in the lower half of the order.
+    pub fn check_malleability(bytes: &[u8]) -> std::result::Result<(), CryptoMaterialError> {
+        let signature = Self::from_bytes_unchecked(bytes)?;
+        if signature.0.s.is_high() {
+            return Err(CryptoMaterialError::CanonicalRepresentationError);
+        }
+        Ok(())
+    }
+}
+
+/// Hashes an arbitrary message into the 32-byte digest signed by ECDSA.
+fn message_digest(message: &[u8]) -> libsecp256k1::Message {
+    let hash = HashValue::sha3_256_of(message);
+    libsecp256k1::Message::parse(hash.as_ref())
+}
+
+///////////////////////
+// PrivateKey Traits //
+///////////////////////
+
+impl PrivateKey for Secp256k1PrivateKey {
+    type PublicKeyMaterial = Secp256k1PublicKey;
+}
+
+impl SigningKey for Secp256k1PrivateKey {
+    type VerifyingKeyMaterial = Secp256k1PublicKey;
+    type SignatureMaterial = Secp256k1Signature;
+
+    fn sign<T: CryptoHash + Serialize>(&self, message: &T) -> Secp256k1Signature {
+        Secp256k1PrivateKey::sign_arbitrary_message(self, signing_message(message).as_ref())
+    }
+
+    #[cfg(any(test, feature = "fuzzing"))]
+    fn sign_arbitrary_message(&self, message: &[u8]) -> Secp256k1Signature {
+        Secp256k1PrivateKey::sign_arbitrary_message(self, message)
+    }
+}
+
+impl Uniform for Secp256k1PrivateKey {
+    fn generate<R>(rng: &mut R) -> Self
+    where
+        R: ::rand::RngCore + ::rand::CryptoRng,
+    {
+        Secp256k1PrivateKey(libsecp256k1::SecretKey::random(rng))
+    }
+}
+
+impl PartialEq<Self> for Secp256k1PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for Secp256k1PrivateKey {}
+
+impl TryFrom<&[u8]> for Secp256k1PrivateKey {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a Secp256k1PrivateKey. This method will also check that the key is a non-zero
+    /// scalar smaller than the order of the curve.
+    fn try_from(bytes: &[u8]) -> std::result::Result<Secp256k1PrivateKey, CryptoMaterialError> {
+        if bytes.len() != SECP256K1_PRIVATE_KEY_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        match libsecp256k1::SecretKey::parse_slice(bytes) {
+            Ok(secret_key) => Ok(Secp256k1PrivateKey(secret_key)),
+            Err(_) => Err(CryptoMaterialError::DeserializationError),
+        }
+    }
+}
+
+impl Length for Secp256k1PrivateKey {
+    fn length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Secp256k1PrivateKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+//////////////////////
+// PublicKey Traits //
+//////////////////////
+
+impl From<&Secp256k1PrivateKey> for Secp256k1PublicKey {
+    fn from(private_key: &Secp256k1PrivateKey) -> Self {
+        Secp256k1PublicKey(libsecp256k1::PublicKey::from_secret_key(&private_key.0))
+    }
+}
+
+impl PublicKey for Secp256k1PublicKey {
+    type PrivateKeyMaterial = Secp256k1PrivateKey;
+}
+
+impl std::hash::Hash for Secp256k1PublicKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let encoded_pubkey = self.to_bytes();
+        state.write(&encoded_pubkey);
+    }
+}
+
+impl PartialEq for Secp256k1PublicKey {
+    fn eq(&self, other: &Secp256k1PublicKey) -> bool {
+        self.to_bytes()[..] == other.to_bytes()[..]
+    }
+}
+
+impl Eq for Secp256k1PublicKey {}
+
+impl VerifyingKey for Secp256k1PublicKey {
+    type SigningKeyMaterial = Secp256k1PrivateKey;
+    type SignatureMaterial = Secp256k1Signature;
+}
+
+impl fmt::Display for Secp256k1PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+impl fmt::Debug for Secp256k1PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256k1PublicKey({})", self)
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1PublicKey {
+    type Error = CryptoMaterialError;
+
+    /// Deserialize a compressed Secp256k1PublicKey. This method will also check that the key is a
+    /// point on the curve.
+    fn try_from(bytes: &[u8]) -> std::result::Result<Secp256k1PublicKey, CryptoMaterialError> {
+        if bytes.len() != SECP256K1_PUBLIC_KEY_LENGTH {
+            return Err(CryptoMaterialError::WrongLengthError);
+        }
+        // A compressed SEC1 point starts with 0x02 or 0x03, depending on the parity of y.
+        if bytes[0] != 0x02 && bytes[0] != 0x03 {
+            return Err(CryptoMaterialError::DeserializationError);
+        }
+        match libsecp256k1::PublicKey::parse_slice(
+            bytes,
+            Some(libsecp256k1::PublicKeyFormat::Compressed),
+        ) {
+            Ok(public_key) => Ok(Secp256k1PublicKey(public_key)),
+            Err(_) => Err(CryptoMaterialError::PointNotOnCurveError),
+        }
+    }
+}
+
+impl Length for Secp256k1PublicKey {
+    fn length(&self) -> usize {
+        SECP256K1_PUBLIC_KEY_LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Secp256k1PublicKey {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+//////////////////////
+// Signature Traits //
+//////////////////////
+
+impl Signature for Secp256k1Signature {
+    type VerifyingKeyMaterial = Secp256k1PublicKey;
+    type SigningKeyMaterial = Secp256k1PrivateKey;
+
+    /// Verifies that the provided signature is valid for the provided message, and that it is in
+    /// canonical (low-`s`) form.
+    fn verify<T: CryptoHash + Serialize>(
+        &self,
+        message: &T,
+        public_key: &Secp256k1PublicKey,
+    ) -> Result<()> {
+        let mut bytes = <T::Hasher as CryptoHasher>::seed().to_vec();
+        bcs::serialize_into(&mut bytes, &message)
+            .map_err(|_| CryptoMaterialError::SerializationError)?;
+        Self::verify_arbitrary_msg(self, &bytes, public_key)
+    }
+
+    /// Checks that `self` is valid for an arbitrary &[u8] `message` using `public_key`.
+    /// Outside of this crate, this particular function should only be used for native signature
+    /// verification in move
+    fn verify_arbitrary_msg(&self, message: &[u8], public_key: &Secp256k1PublicKey) -> Result<()> {
+        Secp256k1Signature::check_malleability(&self.to_bytes())?;
+
+        if libsecp256k1::verify(&message_digest(message), &self.0, &public_key.0) {
+            Ok(())
+        } else {
+            Err(anyhow!("secp256k1 signature verification failed"))
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl Length for Secp256k1Signature {
+    fn length(&self) -> usize {
+        SECP256K1_SIGNATURE_LENGTH
+    }
+}
+
+impl ValidCryptoMaterial for Secp256k1Signature {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+}
+
+impl std::hash::Hash for Secp256k1Signature {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let encoded_signature = self.to_bytes();
+        state.write(&encoded_signature);
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1Signature {
+    type Error = CryptoMaterialError;
+
+    fn try_from(bytes: &[u8]) -> std::result::Result<Secp256k1Signature, CryptoMaterialError> {
+        Secp256k1Signature::check_malleability(bytes)?;
+        Secp256k1Signature::from_bytes_unchecked(bytes)
+    }
+}
+
+impl PartialEq for Secp256k1Signature {
+    fn eq(&self, other: &Secp256k1Signature) -> bool {
+        self.to_bytes()[..] == other.to_bytes()[..]
+    }
+}
+
+impl Eq for Secp256k1Signature {}
+
+impl fmt::Display for Secp256k1Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.to_bytes()[..]))
+    }
+}
+
+impl fmt::Debug for Secp256k1Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256k1Signature({})", self)
+    }
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+use crate::test_utils::{self, KeyPair};
+
+/// Produces a uniformly random secp256k1 keypair from a seed
+#[cfg(any(test, feature = "fuzzing"))]
+pub fn keypair_strategy() -> impl Strategy<Value = KeyPair<Secp256k1PrivateKey, Secp256k1PublicKey>>
+{
+    test_utils::uniform_keypair_strategy::<Secp256k1PrivateKey, Secp256k1PublicKey>()
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::prelude::*;
+
+#[cfg(any(test, feature = "fuzzing"))]
+impl proptest::arbitrary::Arbitrary for Secp256k1PublicKey {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        crate::test_utils::uniform_keypair_strategy::<Secp256k1PrivateKey, Secp256k1PublicKey>()
+            .prop_map(|v| v.public_key)
+            .boxed()
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! An implementation of hierarchical deterministic key derivation as specified in
+//! [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md), for Ed25519 and
+//! secp256k1 private keys.
+//!
+//! SLIP-0010 generalizes [BIP32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki)
+//! to curves other than secp256k1. For secp256k1 it is BIP32-compatible, except in the case of an
+//! invalid derived key: BIP32 then proceeds with the next index, while SLIP-0010 derives again
+//! from the same index.
+//! Ed25519 only supports hardened derivation, i.e. child indices of at least [`HARDENED_OFFSET`].
+//!
+//! Combined with a [BIP39](crate::mnemonic) seed, this allows many accounts to be derived
+//! deterministically from a single secret.
+//!
+//! # Example
+//!
+//! ```
+//! use diem_crypto::{
+//!     ed25519::Ed25519PrivateKey,
+//!     slip0010::{DerivationPath, ExtendedKey},
+//! };
+//! use std::str::FromStr;
+//!
+//! // in production this is recommended to be a 32 bytes or longer random seed.
+//! let seed = [3u8; 32];
+//! let path = DerivationPath::from_str("m/44'/637'/0'/0'/0'").unwrap();
+//! let key = ExtendedKey::<Ed25519PrivateKey>::derive_from_seed(&seed, &path).unwrap();
+//! let _private_key: &Ed25519PrivateKey = key.private_key();
+//! ```
+
+use crate::{
+    ed25519::{Ed25519PrivateKey, Ed25519PublicKey},
+    secp256k1::{Secp256k1PrivateKey, Secp256k1PublicKey},
+    traits::{private, ValidCryptoMaterial},
+};
+use core::convert::TryFrom;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha512;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// Child indices greater or equal to this value denote hardened derivation.
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// The length of a chain code.
+pub const CHAIN_CODE_LENGTH: usize = 32;
+
+/// Seeds shorter than 128 bits or longer than 512 bits are rejected, as in BIP32.
+const MINIMUM_SEED_LENGTH: usize = 16;
+const MAXIMUM_SEED_LENGTH: usize = 64;
+
+/// An error type for SLIP-0010 key derivation issues.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum Slip0010Error {
+    /// The seed is shorter or longer than accepted.
+    #[error("SLIP-0010 error - seed must be between 16 and 64 bytes long")]
+    InvalidSeedLengthError,
+    /// The derivation path could not be parsed.
+    #[error("SLIP-0010 error - invalid derivation path: {0}")]
+    InvalidDerivationPathError(String),
+    /// Non-hardened derivation was requested for a curve that does not support it.
+    #[error(
+        "SLIP-0010 error - non-hardened derivation is not supported for this curve (index {0})"
+    )]
+    NonHardenedDerivationError(u32),
+}
+
+/// A type family of private keys which can be derived according to SLIP-0010.
+///
+/// This trait has a requirement on a `pub(crate)` marker trait meant to
+/// specifically limit its implementations to the present crate.
+pub trait Slip0010Key: ValidCryptoMaterial + private::Sealed + Sized {
+    /// The HMAC key under which the master key is derived from a seed.
+    const CURVE_SEED: &'static [u8];
+
+    /// Whether the curve supports non-hardened child derivation.
+    const SUPPORTS_NON_HARDENED: bool;
+
+    /// Builds the master key from the left half of the master HMAC output, or returns `None` if
+    /// it is not a valid key.
+    fn master_from_il(il: &[u8]) -> Option<Self>;
+
+    /// Builds a child key from the left half of the child HMAC output, or returns `None` if
+    /// the result is not a valid key.
+    fn child_from_il(&self, il: &[u8]) -> Option<Self>;
+
+    /// The serialized public key, used as HMAC input for non-hardened derivation.
+    fn public_key_bytes(&self) -> Vec<u8>;
+}
+
+impl Slip0010Key for Ed25519PrivateKey {
+    const CURVE_SEED: &'static [u8] = b"ed25519 seed";
+    const SUPPORTS_NON_HARDENED: bool = false;
+
+    fn master_from_il(il: &[u8]) -> Option<Self> {
+        Ed25519PrivateKey::try_from(il).ok()
+    }
+
+    fn child_from_il(&self, il: &[u8]) -> Option<Self> {
+        Ed25519PrivateKey::try_from(il).ok()
+    }
+
+    fn public_key_bytes(&self) -> Vec<u8> {
+        Ed25519PublicKey::from(self).to_bytes().to_vec()
+    }
+}
+
+impl Slip0010Key for Secp256k1PrivateKey {
+    const CURVE_SEED: &'static [u8] = b"Bitcoin seed";
+    const SUPPORTS_NON_HARDENED: bool = true;
+
+    fn master_from_il(il: &[u8]) -> Option<Self> {
+        Secp256k1PrivateKey::try_from(il).ok()
+    }
+
+    fn child_from_il(&self, il: &[u8]) -> Option<Self> {
+        // The child key is il + parent (mod n); both il >= n and a zero result are invalid.
+        let tweak = libsecp256k1::SecretKey::parse_slice(il).ok()?;
+        let mut child = libsecp256k1::SecretKey::parse_slice(&self.to_bytes()).ok()?;
+        child.tweak_add_assign(&tweak).ok()?;
+        Secp256k1PrivateKey::try_from(&child.serialize()[..]).ok()
+    }
+
+    fn public_key_bytes(&self) -> Vec<u8> {
+        Secp256k1PublicKey::from(self).to_bytes().to_vec()
+    }
+}
+
+/// A path of child indices, e.g. `m/44'/637'/0'/0'/0'`.
+///
+/// Hardened indices are written with a trailing `'` or `H`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// Creates a derivation path from raw child indices.
+    pub fn new(indices: Vec<u32>) -> Self {
+        DerivationPath(indices)
+    }
+
+    /// Returns the child indices of this path.
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Slip0010Error;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid = || Slip0010Error::InvalidDerivationPathError(path.to_string());
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            return Err(invalid());
+        }
+        components
+            .map(|component| {
+                let (number, hardened) = match component
+                    .strip_suffix('\'')
+                    .or_else(|| component.strip_suffix('H'))
+                {
+                    Some(number) => (number, true),
+                    None => (component, false),
+                };
+                let index = number.parse::<u32>().map_err(|_| invalid())?;
+                if index >= HARDENED_OFFSET {
+                    return Err(invalid());
+                }
+                Ok(if hardened {
+                    index + HARDENED_OFFSET
+                } else {
+                    index
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(DerivationPath)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.0 {
+            if *index >= HARDENED_OFFSET {
+                write!(f, "/{}'", index - HARDENED_OFFSET)?;
+            } else {
+                write!(f, "/{}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A private key extended with a chain code, from which child keys can be derived.
+pub struct ExtendedKey<K: Slip0010Key> {
+    private_key: K,
+    chain_code: [u8; CHAIN_CODE_LENGTH],
+}
+
+impl<K: Slip0010Key> ExtendedKey<K> {
+    /// Derives the master extended key from a seed.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Slip0010Error> {
+        if seed.len() < MINIMUM_SEED_LENGTH || seed.len() > MAXIMUM_SEED_LENGTH {
+            return Err(Slip0010Error::InvalidSeedLengthError);
+        }
+        let mut output = hmac_sha512(K::CURVE_SEED, seed);
+        loop {
+            let (il, ir) = output.split_at(CHAIN_CODE_LENGTH);
+            if let Some(private_key) = K::master_from_il(il) {
+                return Ok(Self::new(private_key, ir));
+            }
+            // The key is invalid, so per SLIP-0010 we hash the output again.
+            output = hmac_sha512(K::CURVE_SEED, &output);
+        }
+    }
+
+    /// Derives the extended key at `path` from a seed.
+    pub fn derive_from_seed(seed: &[u8], path: &DerivationPath) -> Result<Self, Slip0010Error> {
+        Self::from_seed(seed)?.derive_path(path)
+    }
+
+    /// Derives the child extended key at `index`.
+    pub fn derive_child(&self, index: u32) -> Result<Self, Slip0010Error> {
+        let mut data = if index >= HARDENED_OFFSET {
+            let mut data = vec![0u8];
+            data.extend(self.private_key.to_bytes());
+            data
+        } else if K::SUPPORTS_NON_HARDENED {
+            self.private_key.public_key_bytes()
+        } else {
+            return Err(Slip0010Error::NonHardenedDerivationError(index));
+        };
+        data.extend(index.to_be_bytes());
+
+        let mut output = hmac_sha512(&self.chain_code, &data);
+        loop {
+            let (il, ir) = output.split_at(CHAIN_CODE_LENGTH);
+            if let Some(private_key) = self.private_key.child_from_il(il) {
+                return Ok(Self::new(private_key, ir));
+            }
+            // The key is invalid, so per SLIP-0010 we retry with 0x01 || IR || ser32(i).
+            let mut data = vec![1u8];
+            data.extend(ir);
+            data.extend(index.to_be_bytes());
+            output = hmac_sha512(&self.chain_code, &data);
+        }
+    }
+
+    /// Derives the descendant extended key at `path`, relative to this key.
+    pub fn derive_path(self, path: &DerivationPath) -> Result<Self, Slip0010Error> {
+        path.indices()
+            .iter()
+            .try_fold(self, |key, index| key.derive_child(*index))
+    }
+
+    /// Returns the private key.
+    pub fn private_key(&self) -> &K {
+        &self.private_key
+    }
+
+    /// Consumes the extended key, returning the private key.
+    pub fn into_private_key(self) -> K {
+        self.private_key
+    }
+
+    /// Returns the chain code.
+    pub fn chain_code(&self) -> &[u8; CHAIN_CODE_LENGTH] {
+        &self.chain_code
+    }
+
+    fn new(private_key: K, chain_code: &[u8]) -> Self {
+        let mut code = [0u8; CHAIN_CODE_LENGTH];
+        code.copy_from_slice(chain_code);
+        ExtendedKey {
+            private_key,
+            chain_code: code,
+        }
+    }
+}
+
+impl<K: Slip0010Key> fmt::Debug for ExtendedKey<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<elided secret for ExtendedKey>")
+    }
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha512>::new_varkey(key).expect("HMAC should accept keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
@@ -0,0 +1,161 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    bls12381::{
+        Bls12381PrivateKey, Bls12381ProofOfPossession, Bls12381PublicKey, Bls12381Signature,
+        BLS12381_PRIVATE_KEY_LENGTH, BLS12381_PUBLIC_KEY_LENGTH, BLS12381_SIGNATURE_LENGTH,
+    },
+    test_utils::{random_serializable_struct, uniform_keypair_strategy, TestDiemCrypto, TEST_SEED},
+    traits::*,
+};
+
+use core::convert::TryFrom;
+use proptest::{collection::vec, prelude::*};
+use rand::{rngs::StdRng, SeedableRng};
+
+// Helper function to generate N bls12381 private keys.
+fn generate_keys(n: usize) -> Vec<Bls12381PrivateKey> {
+    let mut rng = StdRng::from_seed(TEST_SEED);
+    (0..n)
+        .map(|_| Bls12381PrivateKey::generate(&mut rng))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn test_sign_and_verify(
+        message in random_serializable_struct(),
+        keypair in uniform_keypair_strategy::<Bls12381PrivateKey, Bls12381PublicKey>()
+    ) {
+        let signature = keypair.private_key.sign(&message);
+        prop_assert!(signature.verify(&message, &keypair.public_key).is_ok());
+        prop_assert!(keypair.public_key.verify_struct_signature(&message, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_verify_with_wrong_key_fails(
+        message in random_serializable_struct(),
+        keypair in uniform_keypair_strategy::<Bls12381PrivateKey, Bls12381PublicKey>(),
+        other_keypair in uniform_keypair_strategy::<Bls12381PrivateKey, Bls12381PublicKey>()
+    ) {
+        prop_assume!(keypair.public_key != other_keypair.public_key);
+        let signature = keypair.private_key.sign(&message);
+        prop_assert!(signature.verify(&message, &other_keypair.public_key).is_err());
+    }
+
+    #[test]
+    fn test_keys_and_signature_serialization(
+        message in random_serializable_struct(),
+        keypair in uniform_keypair_strategy::<Bls12381PrivateKey, Bls12381PublicKey>()
+    ) {
+        let private_key_bytes = keypair.private_key.to_bytes();
+        prop_assert_eq!(private_key_bytes.len(), BLS12381_PRIVATE_KEY_LENGTH);
+        let private_key = Bls12381PrivateKey::try_from(&private_key_bytes[..]).unwrap();
+        prop_assert_eq!(&private_key, &keypair.private_key);
+
+        let public_key_bytes = keypair.public_key.to_bytes();
+        prop_assert_eq!(public_key_bytes.len(), BLS12381_PUBLIC_KEY_LENGTH);
+        let public_key = Bls12381PublicKey::try_from(&public_key_bytes[..]).unwrap();
+        prop_assert_eq!(&public_key, &keypair.public_key);
+
+        let signature = keypair.private_key.sign(&message);
+        let signature_bytes = signature.to_bytes();
+        prop_assert_eq!(signature_bytes.len(), BLS12381_SIGNATURE_LENGTH);
+        let deserialized = Bls12381Signature::try_from(&signature_bytes[..]).unwrap();
+        prop_assert_eq!(&deserialized, &signature);
+        prop_assert!(deserialized.verify(&message, &public_key).is_ok());
+
+        let serialized = bcs::to_bytes(&keypair.public_key).unwrap();
+        let deserialized: Bls12381PublicKey = bcs::from_bytes(&serialized).unwrap();
+        prop_assert_eq!(deserialized, keypair.public_key);
+    }
+
+    #[test]
+    fn test_aggregate_signatures(
+        message in random_serializable_struct(),
+        keypairs in vec(uniform_keypair_strategy::<Bls12381PrivateKey, Bls12381PublicKey>(), 1..10)
+    ) {
+        let signatures: Vec<_> = keypairs.iter().map(|kp| kp.private_key.sign(&message)).collect();
+        let public_keys: Vec<_> = keypairs.iter().map(|kp| &kp.public_key).collect();
+
+        let signature_refs: Vec<_> = signatures.iter().collect();
+
+        let aggregate_signature = Bls12381Signature::aggregate(&signature_refs).unwrap();
+        prop_assert!(aggregate_signature.verify_aggregate(&message, &public_keys).is_ok());
+
+        let aggregate_public_key = Bls12381PublicKey::aggregate(&public_keys).unwrap();
+        prop_assert!(aggregate_signature.verify(&message, &aggregate_public_key).is_ok());
+    }
+}
+
+#[test]
+fn test_aggregate_signature_missing_signer_fails() {
+    let message = TestDiemCrypto("Test Message".to_string());
+    let private_keys = generate_keys(4);
+    let public_keys: Vec<_> = private_keys.iter().map(|sk| sk.public_key()).collect();
+
+    // Only the first three signers contribute a signature.
+    let signatures: Vec<_> = private_keys[..3]
+        .iter()
+        .map(|sk| sk.sign(&message))
+        .collect();
+    let signature_refs: Vec<_> = signatures.iter().collect();
+    let aggregate_signature = Bls12381Signature::aggregate(&signature_refs).unwrap();
+
+    let all_signers: Vec<_> = public_keys.iter().collect();
+    assert!(aggregate_signature
+        .verify_aggregate(&message, &all_signers)
+        .is_err());
+    let actual_signers: Vec<_> = public_keys[..3].iter().collect();
+    assert!(aggregate_signature
+        .verify_aggregate(&message, &actual_signers)
+        .is_ok());
+}
+
+#[test]
+fn test_proof_of_possession() {
+    let private_keys = generate_keys(2);
+    let public_key = private_keys[0].public_key();
+    let other_public_key = private_keys[1].public_key();
+
+    let pop = Bls12381ProofOfPossession::create(&private_keys[0]);
+    assert!(pop.verify(&public_key).is_ok());
+    assert!(pop.verify(&other_public_key).is_err());
+
+    let pop_bytes = pop.to_bytes();
+    let deserialized = Bls12381ProofOfPossession::try_from(&pop_bytes[..]).unwrap();
+    assert_eq!(deserialized, pop);
+    assert!(deserialized.verify(&public_key).is_ok());
+
+    // A PoP is not a signature on the public key under the signing domain.
+    let signature = private_keys[0].sign_arbitrary_message(&public_key.to_bytes());
+    let forged_pop = Bls12381ProofOfPossession::try_from(&signature.to_bytes()[..]).unwrap();
+    assert!(forged_pop.verify(&public_key).is_err());
+}
+
+#[test]
+fn test_invalid_material_deserialization() {
+    // Wrong lengths.
+    assert_eq!(
+        Bls12381PrivateKey::try_from(&[1u8; BLS12381_PRIVATE_KEY_LENGTH - 1][..]),
+        Err(CryptoMaterialError::WrongLengthError)
+    );
+    assert_eq!(
+        Bls12381PublicKey::try_from(&[0u8; BLS12381_PUBLIC_KEY_LENGTH + 1][..]),
+        Err(CryptoMaterialError::WrongLengthError)
+    );
+    assert_eq!(
+        Bls12381Signature::try_from(&[0u8; BLS12381_SIGNATURE_LENGTH - 1][..]),
+        Err(CryptoMaterialError::WrongLengthError)
+    );
+
+    // The zero scalar is not a valid private key.
+    assert!(Bls12381PrivateKey::try_from(&[0u8; BLS12381_PRIVATE_KEY_LENGTH][..]).is_err());
+
+    // The point at infinity is not a valid public key.
+    let mut identity = [0u8; BLS12381_PUBLIC_KEY_LENGTH];
+    identity[0] = 0xc0;
+    assert!(Bls12381PublicKey::try_from(&identity[..]).is_err());
+}
@@ -0,0 +1,37 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{mnemonic::Mnemonic, test_utils::TEST_SEED};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_mnemonic_test_vector() {
+    let mnemonic = Mnemonic::from_phrase(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    )
+    .unwrap();
+    assert_eq!(
+        hex::encode(mnemonic.to_seed("TREZOR")),
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+
+    // A bad checksum is rejected.
+    assert!(Mnemonic::from_phrase(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+    )
+    .is_err());
+}
+
+#[test]
+fn test_mnemonic_generation() {
+    let mut rng = StdRng::from_seed(TEST_SEED);
+    for word_count in [12, 15, 18, 21, 24] {
+        let mnemonic = Mnemonic::generate(&mut rng, word_count).unwrap();
+        assert_eq!(mnemonic.phrase().split(' ').count(), word_count);
+        let restored = Mnemonic::from_phrase(&mnemonic.phrase()).unwrap();
+        assert_eq!(mnemonic.to_seed("pass"), restored.to_seed("pass"));
+    }
+    assert!(Mnemonic::generate(&mut rng, 13).is_err());
+}
@@ -3,11 +3,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod bcs_test;
+mod bls12381_test;
 mod compat_test;
 mod cross_test;
 mod cryptohasher;
 mod ed25519_test;
 mod hash_test;
 mod hkdf_test;
+mod mnemonic_test;
 mod multi_ed25519_test;
 mod noise_test;
+mod secp256k1_test;
+mod slip0010_test;
@@ -10,7 +10,7 @@ use crate::{
     CryptoMaterialError::{ValidationError, WrongLengthError},
 };
 
-use crate::multi_ed25519::MultiEd25519Signature;
+use crate::multi_ed25519::{MultiEd25519Signature, MultiEd25519SignatureAggregator};
 use core::convert::TryFrom;
 use once_cell::sync::Lazy;
 use rand::{rngs::StdRng, SeedableRng};
@@ -453,3 +453,61 @@ fn test_multi_ed25519_signature_verification() {
         .verify(message(), &multi_public_key_2of3)
         .is_err());
 }
+
+#[test]
+fn test_signature_aggregator() {
+    let priv_keys_3 = generate_keys(3);
+    let pub_keys_3: Vec<Ed25519PublicKey> = priv_keys_3.iter().map(|x| x.into()).collect();
+    let multi_public_key = MultiEd25519PublicKey::new(pub_keys_3, 2).unwrap();
+
+    let mut aggregator = MultiEd25519SignatureAggregator::new(multi_public_key.clone(), message());
+    assert!(!aggregator.is_complete());
+
+    // Signatures can arrive in any order.
+    aggregator
+        .add_signature(2, priv_keys_3[2].sign(message()))
+        .unwrap();
+    assert!(!aggregator.is_complete());
+    aggregator
+        .add_signature(0, priv_keys_3[0].sign(message()))
+        .unwrap();
+    assert!(aggregator.is_complete());
+    assert_eq!(aggregator.signers(), vec![0, 2]);
+
+    let multi_signature = aggregator.finish().unwrap();
+    assert_eq!(multi_signature.bitmap(), &[0b1010_0000u8, 0u8, 0u8, 0u8]);
+    assert!(multi_signature.verify(message(), &multi_public_key).is_ok());
+}
+
+#[test]
+fn test_signature_aggregator_rejects_bad_contributions() {
+    let priv_keys_3 = generate_keys(3);
+    let pub_keys_3: Vec<Ed25519PublicKey> = priv_keys_3.iter().map(|x| x.into()).collect();
+    let multi_public_key = MultiEd25519PublicKey::new(pub_keys_3, 2).unwrap();
+    let mut aggregator = MultiEd25519SignatureAggregator::new(multi_public_key, message());
+
+    // A signature from the wrong signer, or on another message, is rejected.
+    assert!(aggregator
+        .add_signature(1, priv_keys_3[0].sign(message()))
+        .is_err());
+    let other_message = TestDiemCrypto("Other Message".to_string());
+    assert!(aggregator
+        .add_signature(1, priv_keys_3[1].sign(&other_message))
+        .is_err());
+
+    // Out of range and duplicate indices are rejected.
+    assert!(aggregator
+        .add_signature(3, priv_keys_3[0].sign(message()))
+        .is_err());
+    aggregator
+        .add_signature(1, priv_keys_3[1].sign(message()))
+        .unwrap();
+    assert!(aggregator
+        .add_signature(1, priv_keys_3[1].sign(message()))
+        .is_err());
+    assert_eq!(aggregator.signers(), vec![1]);
+
+    // The threshold is not met.
+    assert!(!aggregator.is_complete());
+    assert!(aggregator.finish().is_err());
+}
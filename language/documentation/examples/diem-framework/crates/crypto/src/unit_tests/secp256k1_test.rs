@@ -0,0 +1,146 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    secp256k1::{
+        Secp256k1PrivateKey, Secp256k1PublicKey, Secp256k1Signature, SECP256K1_PRIVATE_KEY_LENGTH,
+        SECP256K1_PUBLIC_KEY_LENGTH, SECP256K1_SIGNATURE_LENGTH,
+    },
+    test_utils::{random_serializable_struct, uniform_keypair_strategy},
+    traits::*,
+};
+
+use core::convert::TryFrom;
+use proptest::prelude::*;
+
+/// The order of the secp256k1 curve, in big-endian form.
+const N: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+// Computes n - s for a big-endian scalar s < n.
+fn negate_scalar(s: &[u8]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let mut diff = N[i] as i16 - s[i] as i16 - borrow;
+        if diff < 0 {
+            diff += 256;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        result[i] = diff as u8;
+    }
+    result
+}
+
+proptest! {
+    #[test]
+    fn test_sign_and_verify(
+        message in random_serializable_struct(),
+        keypair in uniform_keypair_strategy::<Secp256k1PrivateKey, Secp256k1PublicKey>()
+    ) {
+        let signature = keypair.private_key.sign(&message);
+        prop_assert!(signature.verify(&message, &keypair.public_key).is_ok());
+        prop_assert!(keypair.public_key.verify_struct_signature(&message, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_verify_with_wrong_key_fails(
+        message in random_serializable_struct(),
+        keypair in uniform_keypair_strategy::<Secp256k1PrivateKey, Secp256k1PublicKey>(),
+        other_keypair in uniform_keypair_strategy::<Secp256k1PrivateKey, Secp256k1PublicKey>()
+    ) {
+        prop_assume!(keypair.public_key != other_keypair.public_key);
+        let signature = keypair.private_key.sign(&message);
+        prop_assert!(signature.verify(&message, &other_keypair.public_key).is_err());
+    }
+
+    #[test]
+    fn test_keys_and_signature_serialization(
+        message in random_serializable_struct(),
+        keypair in uniform_keypair_strategy::<Secp256k1PrivateKey, Secp256k1PublicKey>()
+    ) {
+        let private_key_bytes = keypair.private_key.to_bytes();
+        prop_assert_eq!(private_key_bytes.len(), SECP256K1_PRIVATE_KEY_LENGTH);
+        let private_key = Secp256k1PrivateKey::try_from(&private_key_bytes[..]).unwrap();
+        prop_assert_eq!(&private_key, &keypair.private_key);
+
+        let public_key_bytes = keypair.public_key.to_bytes();
+        prop_assert_eq!(public_key_bytes.len(), SECP256K1_PUBLIC_KEY_LENGTH);
+        let public_key = Secp256k1PublicKey::try_from(&public_key_bytes[..]).unwrap();
+        prop_assert_eq!(&public_key, &keypair.public_key);
+
+        let signature = keypair.private_key.sign(&message);
+        let signature_bytes = signature.to_bytes();
+        prop_assert_eq!(signature_bytes.len(), SECP256K1_SIGNATURE_LENGTH);
+        let deserialized = Secp256k1Signature::try_from(&signature_bytes[..]).unwrap();
+        prop_assert_eq!(&deserialized, &signature);
+        prop_assert!(deserialized.verify(&message, &public_key).is_ok());
+
+        let serialized = bcs::to_bytes(&keypair.public_key).unwrap();
+        let deserialized: Secp256k1PublicKey = bcs::from_bytes(&serialized).unwrap();
+        prop_assert_eq!(deserialized, keypair.public_key);
+    }
+
+    #[test]
+    fn test_high_s_signature_is_rejected(
+        message in random_serializable_struct(),
+        keypair in uniform_keypair_strategy::<Secp256k1PrivateKey, Secp256k1PublicKey>()
+    ) {
+        let signature = keypair.private_key.sign(&message);
+        let mut malleated = signature.to_bytes();
+        let high_s = negate_scalar(&malleated[32..]);
+        malleated[32..].copy_from_slice(&high_s);
+
+        prop_assert_eq!(
+            Secp256k1Signature::check_malleability(&malleated),
+            Err(CryptoMaterialError::CanonicalRepresentationError)
+        );
+        prop_assert_eq!(
+            Secp256k1Signature::try_from(&malleated[..]),
+            Err(CryptoMaterialError::CanonicalRepresentationError)
+        );
+    }
+}
+
+#[test]
+fn test_invalid_material_deserialization() {
+    // Wrong lengths.
+    assert_eq!(
+        Secp256k1PrivateKey::try_from(&[1u8; SECP256K1_PRIVATE_KEY_LENGTH - 1][..]),
+        Err(CryptoMaterialError::WrongLengthError)
+    );
+    assert_eq!(
+        Secp256k1PublicKey::try_from(&[2u8; SECP256K1_PUBLIC_KEY_LENGTH + 1][..]),
+        Err(CryptoMaterialError::WrongLengthError)
+    );
+    assert_eq!(
+        Secp256k1Signature::try_from(&[1u8; SECP256K1_SIGNATURE_LENGTH - 1][..]),
+        Err(CryptoMaterialError::WrongLengthError)
+    );
+
+    // Zero and the curve order are not valid private keys.
+    assert!(Secp256k1PrivateKey::try_from(&[0u8; SECP256K1_PRIVATE_KEY_LENGTH][..]).is_err());
+    assert!(Secp256k1PrivateKey::try_from(&N[..]).is_err());
+
+    // Public keys with a prefix other than that of a compressed point are malformed.
+    let mut bad_prefix = [0u8; SECP256K1_PUBLIC_KEY_LENGTH];
+    bad_prefix[0] = 0x04;
+    assert_eq!(
+        Secp256k1PublicKey::try_from(&bad_prefix[..]),
+        Err(CryptoMaterialError::DeserializationError)
+    );
+
+    // There is no point on the curve with x = 5.
+    let mut off_curve = [0u8; SECP256K1_PUBLIC_KEY_LENGTH];
+    off_curve[0] = 0x02;
+    off_curve[SECP256K1_PUBLIC_KEY_LENGTH - 1] = 5;
+    assert_eq!(
+        Secp256k1PublicKey::try_from(&off_curve[..]),
+        Err(CryptoMaterialError::PointNotOnCurveError)
+    );
+}
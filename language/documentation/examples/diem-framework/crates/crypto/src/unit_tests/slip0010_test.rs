@@ -0,0 +1,181 @@
+// Copyright (c) The Diem Core Contributors
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    ed25519::Ed25519PrivateKey,
+    secp256k1::Secp256k1PrivateKey,
+    slip0010::{DerivationPath, ExtendedKey, Slip0010Error, Slip0010Key, HARDENED_OFFSET},
+};
+
+use proptest::prelude::*;
+use std::str::FromStr;
+
+// Seed of test vector 1 from SLIP-0010 (and BIP32).
+const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+// Checks the private key and chain code of each (path, private key, chain code) test vector.
+fn check_test_vectors<K>(vectors: &[(&str, &str, &str)])
+where
+    K: Slip0010Key,
+{
+    let seed = hex::decode(SEED).unwrap();
+    for (path, private_key, chain_code) in vectors {
+        let path = DerivationPath::from_str(path).unwrap();
+        let key = ExtendedKey::<K>::derive_from_seed(&seed, &path).unwrap();
+        assert_eq!(hex::encode(key.private_key().to_bytes()), *private_key);
+        assert_eq!(hex::encode(key.chain_code()), *chain_code);
+    }
+}
+
+#[test]
+fn test_ed25519_test_vector_1() {
+    check_test_vectors::<Ed25519PrivateKey>(&[
+        (
+            "m",
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+            "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+        ),
+        (
+            "m/0'",
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+            "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+        ),
+        (
+            "m/0'/1'",
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+            "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+        ),
+        (
+            "m/0'/1'/2'",
+            "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9",
+            "2e69929e00b5ab250f49c3fb1c12f252de4fed2c1db88387094a0f8c4c9ccd6c",
+        ),
+        (
+            "m/0'/1'/2'/2'",
+            "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662",
+            "8f6d87f93d750e0efccda017d662a1b31a266e4a6f5993b15f5c1f07f74dd5cc",
+        ),
+        (
+            "m/0'/1'/2'/2'/1000000000'",
+            "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+            "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+        ),
+    ]);
+}
+
+#[test]
+fn test_secp256k1_test_vector_1() {
+    check_test_vectors::<Secp256k1PrivateKey>(&[
+        (
+            "m",
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+        ),
+        (
+            "m/0H",
+            "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+            "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+        ),
+        (
+            "m/0H/1",
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+        ),
+        (
+            "m/0H/1/2H",
+            "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+            "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+        ),
+        (
+            "m/0H/1/2H/2",
+            "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4",
+            "cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd",
+        ),
+        (
+            "m/0H/1/2H/2/1000000000",
+            "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+            "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+        ),
+    ]);
+}
+
+#[test]
+fn test_ed25519_non_hardened_derivation_fails() {
+    let seed = hex::decode(SEED).unwrap();
+    let master = ExtendedKey::<Ed25519PrivateKey>::from_seed(&seed).unwrap();
+    assert_eq!(
+        master.derive_child(1).unwrap_err(),
+        Slip0010Error::NonHardenedDerivationError(1)
+    );
+}
+
+#[test]
+fn test_invalid_seed_length() {
+    assert_eq!(
+        ExtendedKey::<Ed25519PrivateKey>::from_seed(&[0u8; 15]).unwrap_err(),
+        Slip0010Error::InvalidSeedLengthError
+    );
+    assert_eq!(
+        ExtendedKey::<Secp256k1PrivateKey>::from_seed(&[0u8; 65]).unwrap_err(),
+        Slip0010Error::InvalidSeedLengthError
+    );
+}
+
+#[test]
+fn test_derivation_path_parsing() {
+    let path = DerivationPath::from_str("m/44'/637'/0H/1").unwrap();
+    assert_eq!(
+        path.indices(),
+        &[
+            44 + HARDENED_OFFSET,
+            637 + HARDENED_OFFSET,
+            HARDENED_OFFSET,
+            1
+        ]
+    );
+    assert_eq!(path.to_string(), "m/44'/637'/0'/1");
+    assert_eq!(
+        DerivationPath::from_str("m").unwrap(),
+        DerivationPath::default()
+    );
+
+    for invalid in ["", "44'/0'", "m/", "m/a'", "m/2147483648", "m/-1", "m//0"] {
+        assert!(
+            DerivationPath::from_str(invalid).is_err(),
+            "{} should not parse",
+            invalid
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_derivation_is_deterministic_and_path_dependent(
+        seed in any::<[u8; 32]>(),
+        account in 0u32..HARDENED_OFFSET,
+    ) {
+        let path = DerivationPath::new(vec![44 + HARDENED_OFFSET, account + HARDENED_OFFSET]);
+        let other_path = DerivationPath::new(vec![44 + HARDENED_OFFSET, (account + 1) % HARDENED_OFFSET + HARDENED_OFFSET]);
+
+        let key = ExtendedKey::<Ed25519PrivateKey>::derive_from_seed(&seed, &path).unwrap();
+        let same_key = ExtendedKey::<Ed25519PrivateKey>::derive_from_seed(&seed, &path).unwrap();
+        let other_key = ExtendedKey::<Ed25519PrivateKey>::derive_from_seed(&seed, &other_path).unwrap();
+        prop_assert_eq!(key.private_key(), same_key.private_key());
+        prop_assert_ne!(key.private_key(), other_key.private_key());
+
+        // Deriving step by step matches deriving the full path at once.
+        let master = ExtendedKey::<Secp256k1PrivateKey>::from_seed(&seed).unwrap();
+        let stepwise = master
+            .derive_child(44 + HARDENED_OFFSET)
+            .and_then(|key| key.derive_child(account))
+            .unwrap();
+        let direct = ExtendedKey::<Secp256k1PrivateKey>::derive_from_seed(
+            &seed,
+            &DerivationPath::new(vec![44 + HARDENED_OFFSET, account]),
+        )
+        .unwrap();
+        prop_assert_eq!(stepwise.private_key(), direct.private_key());
+        prop_assert_eq!(stepwise.chain_code(), direct.chain_code());
+    }
+}
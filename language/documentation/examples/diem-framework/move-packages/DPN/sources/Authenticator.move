@@ -1,5 +1,5 @@
-/// Move representation of the authenticator types used in Diem. The supported types are Ed25519 (single-sig)
-/// and MultiEd25519 (K-of-N multisig).
+/// Move representation of the authenticator types used in Diem. The supported types are Ed25519 (single-sig),
+/// MultiEd25519 (K-of-N multisig) and Secp256k1Ecdsa (single-sig).
 module DiemFramework::Authenticator {
     use std::errors;
     use std::hash;
@@ -18,6 +18,8 @@ module DiemFramework::Authenticator {
     const SINGLE_ED25519_SCHEME_ID: u8 = 0;
     /// Scheme byte ID for multi-ed25519
     const MULTI_ED25519_SCHEME_ID: u8 = 1;
+    /// Scheme byte ID for secp256k1 ECDSA
+    const SINGLE_SECP256K1_ECDSA_SCHEME_ID: u8 = 2;
 
     /// Maximum number of keys allowed in a MultiEd25519 public/private key
     const MAX_MULTI_ED25519_KEYS: u64 = 32;
@@ -68,6 +70,20 @@ module DiemFramework::Authenticator {
     /// does not matter for the verification of callers.
     spec fun spec_ed25519_authentication_key(public_key: vector<u8>): vector<u8>;
 
+    /// Compute an authentication key for the compressed secp256k1 ECDSA public key `public_key`
+    public fun secp256k1_ecdsa_authentication_key(public_key: vector<u8>): vector<u8> {
+        vector::push_back(&mut public_key, SINGLE_SECP256K1_ECDSA_SCHEME_ID);
+        hash::sha3_256(public_key)
+    }
+    spec secp256k1_ecdsa_authentication_key {
+        pragma opaque = true;
+        aborts_if false;
+        ensures [abstract] result == spec_secp256k1_ecdsa_authentication_key(public_key);
+    }
+    /// We use an uninterpreted function to represent the result of key construction. The actual value
+    /// does not matter for the verification of callers.
+    spec fun spec_secp256k1_ecdsa_authentication_key(public_key: vector<u8>): vector<u8>;
+
     /// Compute a multied25519 account authentication key for the policy `k`
     public fun multi_ed25519_authentication_key(k: &MultiEd25519PublicKey): vector<u8> {
         let public_keys = &k.public_keys;
@@ -14,6 +14,7 @@ module DiemFramework::DiemConfig {
     friend DiemFramework::DiemSystem;
     friend DiemFramework::DiemConsensusConfig;
     friend DiemFramework::ParallelExecutionConfig;
+    friend DiemFramework::DiemFeatureFlags;
 
     /// A generic singleton resource that holds a value of a specific type.
     struct DiemConfig<Config: copy + drop + store> has key, store {
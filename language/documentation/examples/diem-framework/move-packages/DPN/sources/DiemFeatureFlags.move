@@ -0,0 +1,136 @@
+/// Maintains the set of protocol features enabled on the Diem blockchain. The set is stored in a
+/// DiemConfig, and may be updated by Diem root.
+///
+/// Features are identified by a `u64` chosen by the code gating its behavior on the feature.
+/// Move code checks a feature with `is_enabled`, which reads the stored set and so observes a
+/// change immediately, within the same transaction. Enabling or disabling a feature also triggers
+/// a reconfiguration, so the VM picks up the change at the start of the next epoch.
+module DiemFramework::DiemFeatureFlags {
+    use DiemFramework::DiemConfig::{Self, DiemConfig};
+    use DiemFramework::DiemTimestamp;
+    use DiemFramework::Roles;
+    use std::errors;
+    use std::vector;
+
+    struct DiemFeatureFlags has copy, drop, store {
+        /// The identifiers of the enabled features, in the order they were enabled.
+        enabled_features: vector<u64>,
+    }
+
+    /// Tried to enable a feature which is already enabled.
+    const EFEATURE_ALREADY_ENABLED: u64 = 0;
+    /// Tried to disable a feature which is not enabled.
+    const EFEATURE_NOT_ENABLED: u64 = 1;
+
+    /// Publishes the DiemFeatureFlags config with no feature enabled. Must be called during Genesis.
+    public fun initialize(dr_account: &signer) {
+        DiemTimestamp::assert_genesis();
+        Roles::assert_diem_root(dr_account);
+        DiemConfig::publish_new_config<DiemFeatureFlags>(
+            dr_account,
+            DiemFeatureFlags { enabled_features: vector::empty() },
+        );
+    }
+    spec initialize {
+        /// Must abort if the signer does not have the DiemRoot role [[H11]][PERMISSION].
+        include Roles::AbortsIfNotDiemRoot{account: dr_account};
+
+        include DiemTimestamp::AbortsIfNotGenesis;
+        include DiemConfig::PublishNewConfigAbortsIf<DiemFeatureFlags>;
+        include DiemConfig::PublishNewConfigEnsures<DiemFeatureFlags>{
+            payload: DiemFeatureFlags { enabled_features: vec() }
+        };
+    }
+
+    /// Returns true if `feature` is enabled.
+    public fun is_enabled(feature: u64): bool {
+        let config = DiemConfig::get<DiemFeatureFlags>();
+        vector::contains(&config.enabled_features, &feature)
+    }
+    spec is_enabled {
+        pragma opaque;
+        include DiemConfig::AbortsIfNotPublished<DiemFeatureFlags>;
+        ensures result == spec_is_enabled(feature);
+    }
+
+    /// Allows Diem root to enable `feature`.
+    public fun enable(dr_account: &signer, feature: u64) {
+        DiemTimestamp::assert_operating();
+        Roles::assert_diem_root(dr_account);
+
+        let config = DiemConfig::get<DiemFeatureFlags>();
+        assert!(
+            !vector::contains(&config.enabled_features, &feature),
+            errors::invalid_argument(EFEATURE_ALREADY_ENABLED)
+        );
+        vector::push_back(&mut config.enabled_features, feature);
+
+        DiemConfig::set<DiemFeatureFlags>(dr_account, config);
+    }
+    spec enable {
+        /// Must abort if the signer does not have the DiemRoot role [[H11]][PERMISSION].
+        include Roles::AbortsIfNotDiemRoot{account: dr_account};
+
+        include DiemTimestamp::AbortsIfNotOperating;
+        aborts_if spec_is_enabled(feature) with errors::INVALID_ARGUMENT;
+        include DiemConfig::SetAbortsIf<DiemFeatureFlags>{account: dr_account};
+        ensures spec_is_enabled(feature);
+        /// No other feature is enabled or disabled.
+        ensures forall f: u64 where f != feature: spec_is_enabled(f) == old(spec_is_enabled(f));
+    }
+
+    /// Allows Diem root to disable `feature`.
+    public fun disable(dr_account: &signer, feature: u64) {
+        DiemTimestamp::assert_operating();
+        Roles::assert_diem_root(dr_account);
+
+        let config = DiemConfig::get<DiemFeatureFlags>();
+        let (found, index) = vector::index_of(&config.enabled_features, &feature);
+        assert!(found, errors::invalid_argument(EFEATURE_NOT_ENABLED));
+        vector::remove(&mut config.enabled_features, index);
+
+        DiemConfig::set<DiemFeatureFlags>(dr_account, config);
+    }
+    spec disable {
+        /// Must abort if the signer does not have the DiemRoot role [[H11]][PERMISSION].
+        include Roles::AbortsIfNotDiemRoot{account: dr_account};
+
+        include DiemTimestamp::AbortsIfNotOperating;
+        aborts_if !spec_is_enabled(feature) with errors::INVALID_ARGUMENT;
+        include DiemConfig::SetAbortsIf<DiemFeatureFlags>{account: dr_account};
+        ensures !spec_is_enabled(feature);
+        /// No other feature is enabled or disabled.
+        ensures forall f: u64 where f != feature: spec_is_enabled(f) == old(spec_is_enabled(f));
+    }
+
+    // =================================================================
+    // Module Specification
+
+    spec module {} // Switch to module documentation context
+
+    spec module {
+        /// Returns true if `feature` is enabled.
+        fun spec_is_enabled(feature: u64): bool {
+            contains(DiemConfig::get<DiemFeatureFlags>().enabled_features, feature)
+        }
+    }
+
+    /// # Initialization
+    spec module {
+        /// After genesis, the feature flags are published.
+        invariant [suspendable] DiemTimestamp::is_operating() ==> DiemConfig::spec_is_published<DiemFeatureFlags>();
+    }
+
+    /// # Access Control
+
+    /// The permission "UpdateDiemFeatureFlags" is granted to DiemRoot [[H11]][PERMISSION].
+    spec module {
+        invariant [suspendable] forall addr: address
+            where exists<DiemConfig<DiemFeatureFlags>>(addr): addr == @DiemRoot;
+
+        invariant update [suspendable] old(DiemConfig::spec_is_published<DiemFeatureFlags>())
+            && DiemConfig::spec_is_published<DiemFeatureFlags>()
+            && old(DiemConfig::get<DiemFeatureFlags>()) != DiemConfig::get<DiemFeatureFlags>()
+                ==> Roles::spec_signed_by_diem_root_role();
+    }
+}
@@ -14,6 +14,7 @@ module DiemFramework::Genesis {
     use DiemFramework::DiemBlock;
     use DiemFramework::DiemConfig;
     use DiemFramework::DiemConsensusConfig;
+    use DiemFramework::DiemFeatureFlags;
     use DiemFramework::DiemSystem;
     use DiemFramework::DiemTimestamp;
     use DiemFramework::DiemTransactionPublishingOption;
@@ -82,6 +83,9 @@ module DiemFramework::Genesis {
         // Parallel execution config setup
         ParallelExecutionConfig::initialize_parallel_execution(dr_account);
 
+        // Feature flags setup
+        DiemFeatureFlags::initialize(dr_account);
+
         // Currency setup
         Diem::initialize(dr_account);
 
@@ -2,6 +2,7 @@
 /// network outside of validators and validator operators.
 module DiemFramework::SystemAdministrationScripts {
     use DiemFramework::DiemConsensusConfig;
+    use DiemFramework::DiemFeatureFlags;
     use DiemFramework::DiemVersion;
     use DiemFramework::DiemVMConfig;
     use DiemFramework::SlidingNonce;
@@ -160,4 +161,66 @@ module DiemFramework::SystemAdministrationScripts {
         SlidingNonce::record_nonce_or_abort(&account, sliding_nonce);
         DiemConsensusConfig::set(&account, config)
     }
+
+    ///  # Summary
+    /// Enables a protocol feature in the Diem feature flags that are stored on-chain.  This
+    /// transaction can only be sent from the Diem Root account.
+    ///
+    /// # Technical Description
+    /// Adds `feature` to the `DiemFeatureFlags` on-chain config and emits a `DiemConfig::NewEpochEvent`
+    /// to trigger a reconfiguration of the system. Move code observes the feature as enabled from this
+    /// transaction on, and the VM from the next epoch.
+    ///
+    /// # Parameters
+    /// | Name            | Type     | Description                                                                |
+    /// | ------          | ------   | -------------                                                              |
+    /// | `account`       | `signer` | Signer of the sending account. Must be the Diem Root account.              |
+    /// | `sliding_nonce` | `u64`    | The `sliding_nonce` (see: `SlidingNonce`) to be used for this transaction. |
+    /// | `feature`       | `u64`    | The identifier of the feature to enable.                                   |
+    ///
+    /// # Common Abort Conditions
+    /// | Error Category             | Error Reason                                   | Description                                                                                |
+    /// | ----------------           | --------------                                 | -------------                                                                              |
+    /// | `Errors::NOT_PUBLISHED`    | `SlidingNonce::ESLIDING_NONCE`                 | A `SlidingNonce` resource is not published under `account`.                                |
+    /// | `Errors::INVALID_ARGUMENT` | `SlidingNonce::ENONCE_TOO_OLD`                 | The `sliding_nonce` is too old and it's impossible to determine if it's duplicated or not. |
+    /// | `Errors::INVALID_ARGUMENT` | `SlidingNonce::ENONCE_TOO_NEW`                 | The `sliding_nonce` is too far in the future.                                              |
+    /// | `Errors::INVALID_ARGUMENT` | `SlidingNonce::ENONCE_ALREADY_RECORDED`        | The `sliding_nonce` has been previously recorded.                                          |
+    /// | `Errors::REQUIRES_ADDRESS` | `CoreAddresses::EDIEM_ROOT`                    | `account` is not the Diem Root account.                                                    |
+    /// | `Errors::INVALID_ARGUMENT` | `DiemFeatureFlags::EFEATURE_ALREADY_ENABLED`   | `feature` is already enabled.                                                              |
+
+    public entry fun enable_feature(account: signer, sliding_nonce: u64, feature: u64) {
+        SlidingNonce::record_nonce_or_abort(&account, sliding_nonce);
+        DiemFeatureFlags::enable(&account, feature)
+    }
+
+    ///  # Summary
+    /// Disables a protocol feature in the Diem feature flags that are stored on-chain.  This
+    /// transaction can only be sent from the Diem Root account.
+    ///
+    /// # Technical Description
+    /// Removes `feature` from the `DiemFeatureFlags` on-chain config and emits a `DiemConfig::NewEpochEvent`
+    /// to trigger a reconfiguration of the system. Move code observes the feature as disabled from this
+    /// transaction on, and the VM from the next epoch.
+    ///
+    /// # Parameters
+    /// | Name            | Type     | Description                                                                |
+    /// | ------          | ------   | -------------                                                              |
+    /// | `account`       | `signer` | Signer of the sending account. Must be the Diem Root account.              |
+    /// | `sliding_nonce` | `u64`    | The `sliding_nonce` (see: `SlidingNonce`) to be used for this transaction. |
+    /// | `feature`       | `u64`    | The identifier of the feature to disable.                                  |
+    ///
+    /// # Common Abort Conditions
+    /// | Error Category             | Error Reason                                   | Description                                                                                |
+    /// | ----------------           | --------------                                 | -------------                                                                              |
+    /// | `Errors::NOT_PUBLISHED`    | `SlidingNonce::ESLIDING_NONCE`                 | A `SlidingNonce` resource is not published under `account`.                                |
+    /// | `Errors::INVALID_ARGUMENT` | `SlidingNonce::ENONCE_TOO_OLD`                 | The `sliding_nonce` is too old and it's impossible to determine if it's duplicated or not. |
+    /// | `Errors::INVALID_ARGUMENT` | `SlidingNonce::ENONCE_TOO_NEW`                 | The `sliding_nonce` is too far in the future.                                              |
+    /// | `Errors::INVALID_ARGUMENT` | `SlidingNonce::ENONCE_ALREADY_RECORDED`        | The `sliding_nonce` has been previously recorded.                                          |
+    /// | `Errors::REQUIRES_ADDRESS` | `CoreAddresses::EDIEM_ROOT`                    | `account` is not the Diem Root account.                                                    |
+    /// | `Errors::INVALID_ARGUMENT` | `DiemFeatureFlags::EFEATURE_NOT_ENABLED`       | `feature` is not enabled.                                                                  |
+
+    public entry fun disable_feature(account: signer, sliding_nonce: u64, feature: u64) {
+        SlidingNonce::record_nonce_or_abort(&account, sliding_nonce);
+        DiemFeatureFlags::disable(&account, feature)
+    }
 }
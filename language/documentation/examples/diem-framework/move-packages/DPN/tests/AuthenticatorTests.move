@@ -115,4 +115,21 @@ module DiemFramework::AuthenticatorTests {
             3012
         );
     }
+
+    #[test]
+    fun secp256k1_ecdsa_auth_key_should_differ_from_ed25519_auth_key_with_same_bytes() {
+        // The compressed encoding of the secp256k1 generator point.
+        let pubkey = x"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        assert!(
+            Authenticator::secp256k1_ecdsa_authentication_key(copy pubkey) !=
+                Authenticator::ed25519_authentication_key(copy pubkey),
+            3013
+        );
+        assert!(
+            x"ec4767415dccb514a4bb70cc011a73b6ed87aa6b5f4a354c9577a096e3829cd6"
+            ==
+            Authenticator::secp256k1_ecdsa_authentication_key(pubkey),
+            3014
+        );
+    }
 }
@@ -0,0 +1,103 @@
+#[test_only]
+module DiemFramework::DiemFeatureFlagsTests {
+    use DiemFramework::DiemFeatureFlags;
+    use DiemFramework::Genesis;
+    use DiemFramework::SlidingNonce;
+    use DiemFramework::SystemAdministrationScripts;
+
+    #[test(account = @0x1)]
+    #[expected_failure(abort_code = 2, location = DiemFramework::CoreAddresses)]
+    fun init_before_genesis(account: signer) {
+        DiemFeatureFlags::initialize(&account);
+    }
+
+    #[test(account = @0x1)]
+    #[expected_failure(abort_code = 257, location = DiemFramework::DiemTimestamp)]
+    fun enable_before_genesis(account: signer) {
+        DiemFeatureFlags::enable(&account, 0);
+    }
+
+    #[test(account = @0x2, tc = @TreasuryCompliance, dr = @DiemRoot)]
+    #[expected_failure(abort_code = 1, location = DiemFramework::DiemTimestamp)]
+    fun invalid_address_init(account: signer, tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        DiemFeatureFlags::initialize(&account);
+    }
+
+    #[test(account = @0x2, tc = @TreasuryCompliance, dr = @DiemRoot)]
+    #[expected_failure(abort_code = 2, location = DiemFramework::CoreAddresses)]
+    fun invalid_enabling_address(account: signer, tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        DiemFeatureFlags::enable(&account, 0);
+    }
+
+    #[test(account = @0x2, tc = @TreasuryCompliance, dr = @DiemRoot)]
+    #[expected_failure(abort_code = 2, location = DiemFramework::CoreAddresses)]
+    fun invalid_disabling_address(account: signer, tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        DiemFeatureFlags::enable(&dr, 0);
+        DiemFeatureFlags::disable(&account, 0);
+    }
+
+    #[test(tc = @TreasuryCompliance, dr = @DiemRoot)]
+    fun enable_and_disable(tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        assert!(!DiemFeatureFlags::is_enabled(0), 0);
+        assert!(!DiemFeatureFlags::is_enabled(1), 1);
+
+        // Changes are visible to Move code immediately, within the same transaction.
+        DiemFeatureFlags::enable(&dr, 1);
+        assert!(!DiemFeatureFlags::is_enabled(0), 2);
+        assert!(DiemFeatureFlags::is_enabled(1), 3);
+
+        DiemFeatureFlags::enable(&dr, 0);
+        assert!(DiemFeatureFlags::is_enabled(0), 4);
+        assert!(DiemFeatureFlags::is_enabled(1), 5);
+
+        DiemFeatureFlags::disable(&dr, 1);
+        assert!(DiemFeatureFlags::is_enabled(0), 6);
+        assert!(!DiemFeatureFlags::is_enabled(1), 7);
+
+        // A disabled feature can be enabled again.
+        DiemFeatureFlags::enable(&dr, 1);
+        assert!(DiemFeatureFlags::is_enabled(1), 8);
+    }
+
+    #[test(tc = @TreasuryCompliance, dr = @DiemRoot)]
+    #[expected_failure(abort_code = 7, location = DiemFeatureFlags)]
+    fun enable_twice(tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        DiemFeatureFlags::enable(&dr, 0);
+        DiemFeatureFlags::enable(&dr, 0);
+    }
+
+    #[test(tc = @TreasuryCompliance, dr = @DiemRoot)]
+    #[expected_failure(abort_code = 263, location = DiemFeatureFlags)]
+    fun disable_not_enabled(tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        DiemFeatureFlags::disable(&dr, 0);
+    }
+
+    #[test(tc = @TreasuryCompliance, dr = @DiemRoot)]
+    fun enable_through_script(tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        SystemAdministrationScripts::enable_feature(dr, 1, 0);
+        assert!(DiemFeatureFlags::is_enabled(0), 0);
+    }
+
+    #[test(tc = @TreasuryCompliance, dr = @DiemRoot)]
+    fun disable_through_script(tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        DiemFeatureFlags::enable(&dr, 0);
+        SystemAdministrationScripts::disable_feature(dr, 1, 0);
+        assert!(!DiemFeatureFlags::is_enabled(0), 0);
+    }
+
+    #[test(tc = @TreasuryCompliance, dr = @DiemRoot)]
+    #[expected_failure(abort_code = 775, location = DiemFramework::SlidingNonce)]
+    fun script_with_recorded_nonce(tc: signer, dr: signer) {
+        Genesis::setup(&dr, &tc);
+        SlidingNonce::record_nonce_or_abort(&dr, 1);
+        SystemAdministrationScripts::enable_feature(dr, 1, 0);
+    }
+}